@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+/// Consensus-related configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Consensus {
+    pub min_block_production_delay: Duration,
+    pub max_block_production_delay: Duration,
+    pub max_block_wait_delay: Duration,
+    pub header_sync_expected_height_per_second: u64,
+}
+
+impl Default for Consensus {
+    fn default() -> Self {
+        Self {
+            min_block_production_delay: Duration::from_millis(600),
+            max_block_production_delay: Duration::from_millis(2000),
+            max_block_wait_delay: Duration::from_millis(6000),
+            header_sync_expected_height_per_second: 10,
+        }
+    }
+}
+
+/// Garbage-collection configuration.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GcConfig {
+    pub gc_blocks_limit: u64,
+    pub gc_fork_clean_step: u64,
+    pub gc_num_epochs_to_keep: u64,
+}
+
+impl Default for GcConfig {
+    fn default() -> Self {
+        Self { gc_blocks_limit: 2, gc_fork_clean_step: 100, gc_num_epochs_to_keep: 5 }
+    }
+}
+
+/// Networking configuration: listen address, peer lists, and buffering/payload limits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NetworkConfig {
+    pub addr: String,
+    pub external_address: String,
+    pub boot_nodes: String,
+    pub whitelist_nodes: String,
+    pub max_payload_size: Option<u64>,
+    /// Size, in bytes, of the per-peer receive buffer the network actor allocates for
+    /// incoming messages. Must be at least `max_payload_size` or a large message can
+    /// deadlock or be silently dropped.
+    pub peer_recv_buffer_size: u64,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            addr: String::new(),
+            external_address: String::new(),
+            boot_nodes: String::new(),
+            whitelist_nodes: String::new(),
+            max_payload_size: None,
+            peer_recv_buffer_size: 8 * 1024 * 1024,
+        }
+    }
+}
+
+/// Top-level node configuration, deserialized from `config.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Schema version of this config, bumped by `config_migrations::migrate_config` as it
+    /// walks a config.json forward to the version this binary expects.
+    pub config_version: u32,
+    pub archive: bool,
+    pub save_trie_changes: Option<bool>,
+    pub tracked_shards: Vec<u64>,
+    pub consensus: Consensus,
+    pub gc: GcConfig,
+    pub network: NetworkConfig,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            config_version: crate::config_migrations::CONFIG_VERSION,
+            archive: true,
+            save_trie_changes: None,
+            tracked_shards: Vec::new(),
+            consensus: Consensus::default(),
+            gc: GcConfig::default(),
+            network: NetworkConfig::default(),
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use near_config_utils::ValidationError;
+use serde_json::Value;
+
+/// The current config schema version. Bump this whenever a migration step is appended below.
+pub const CONFIG_VERSION: u32 = 3;
+
+type MigrationFn = fn(Value) -> Value;
+
+/// Ordered chain of per-version transforms. The migration at index `i` turns a config at
+/// schema version `i + 1` into one at version `i + 2`, mirroring how parachain runtimes
+/// apply successive `MigrateToVN` steps rather than one big rewrite.
+const MIGRATIONS: &[MigrationFn] = &[migrate_v1_to_v2, migrate_v2_to_v3];
+
+/// Applies every migration step needed to bring `raw` config.json up to [`CONFIG_VERSION`],
+/// returning JSON ready for deserialization into [`crate::config::Config`].
+///
+/// Configs with no `config_version` field are treated as version 1. Returns a
+/// [`ValidationError`] if the on-disk version is newer than this binary supports, since
+/// downgrading a config is not something we can do safely.
+pub fn migrate_config(raw: Value) -> Result<Value, ValidationError> {
+    let mut value = raw;
+    let mut version = read_version(&value);
+
+    if version == 0 {
+        return Err(ValidationError::ConfigSemanticsError {
+            error_message: "config.json has config_version 0, which is not a valid schema \
+                 version; versions start at 1"
+                .to_string(),
+        });
+    }
+
+    if version > CONFIG_VERSION {
+        return Err(ValidationError::ConfigSemanticsError {
+            error_message: format!(
+                "config.json has config_version {} but this binary only supports up to {}; \
+                 upgrade nearcore before loading this config",
+                version, CONFIG_VERSION
+            ),
+        });
+    }
+
+    while version < CONFIG_VERSION {
+        let migrate = MIGRATIONS[(version - 1) as usize];
+        value = migrate(value);
+        version += 1;
+        set_version(&mut value, version);
+    }
+
+    Ok(value)
+}
+
+fn read_version(value: &Value) -> u32 {
+    value
+        .get("config_version")
+        .and_then(Value::as_u64)
+        .map(|v| u32::try_from(v).unwrap_or(u32::MAX))
+        .unwrap_or(1)
+}
+
+fn set_version(value: &mut Value, version: u32) {
+    if let Some(obj) = value.as_object_mut() {
+        obj.insert("config_version".to_string(), Value::from(version));
+    }
+}
+
+/// v1 -> v2: `consensus.block_fetch_horizon` was renamed to
+/// `consensus.header_sync_expected_height_per_second`.
+fn migrate_v1_to_v2(mut value: Value) -> Value {
+    if let Some(consensus) = value.get_mut("consensus").and_then(Value::as_object_mut) {
+        if let Some(old) = consensus.remove("block_fetch_horizon") {
+            consensus.entry("header_sync_expected_height_per_second".to_string()).or_insert(old);
+        }
+    }
+    value
+}
+
+/// v2 -> v3: `gc.num_epochs_to_keep` was renamed to `gc.gc_num_epochs_to_keep` for consistency
+/// with the other `gc_*` field names.
+fn migrate_v2_to_v3(mut value: Value) -> Value {
+    if let Some(gc) = value.get_mut("gc").and_then(Value::as_object_mut) {
+        if let Some(old) = gc.remove("num_epochs_to_keep") {
+            gc.entry("gc_num_epochs_to_keep".to_string()).or_insert(old);
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_migrate_v1_to_current() {
+        let raw = json!({
+            "consensus": { "block_fetch_horizon": 1000 },
+        });
+        let migrated = migrate_config(raw).unwrap();
+        assert_eq!(migrated["config_version"], json!(CONFIG_VERSION));
+        assert_eq!(migrated["consensus"]["header_sync_expected_height_per_second"], json!(1000));
+        assert!(migrated["consensus"].get("block_fetch_horizon").is_none());
+    }
+
+    #[test]
+    fn test_migrate_renames_gc_num_epochs_to_keep() {
+        let raw = json!({ "config_version": 2, "gc": { "num_epochs_to_keep": 5 } });
+        let migrated = migrate_config(raw).unwrap();
+        assert_eq!(migrated["gc"]["gc_num_epochs_to_keep"], json!(5));
+        assert!(migrated["gc"].get("num_epochs_to_keep").is_none());
+    }
+
+    #[test]
+    fn test_future_version_rejected() {
+        let raw = json!({ "config_version": CONFIG_VERSION + 1 });
+        assert!(migrate_config(raw).is_err());
+    }
+
+    #[test]
+    fn test_zero_version_rejected_without_underflow() {
+        let raw = json!({ "config_version": 0 });
+        assert!(migrate_config(raw).is_err());
+    }
+
+    #[test]
+    fn test_already_current_version_is_noop() {
+        let raw = json!({ "config_version": CONFIG_VERSION, "archive": true });
+        let migrated = migrate_config(raw.clone()).unwrap();
+        assert_eq!(migrated, raw);
+    }
+}
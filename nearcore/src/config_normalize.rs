@@ -0,0 +1,136 @@
+use crate::config::Config;
+use crate::config_rules;
+
+/// One field that [`normalize_config`] rewrote, recorded so operators can review (and persist)
+/// the result rather than having it applied silently.
+#[derive(Debug, PartialEq, Eq)]
+pub struct NormalizationChange {
+    pub field: String,
+    pub old_value: String,
+    pub new_value: String,
+}
+
+/// Report of every auto-repair [`normalize_config`] applied to a [`Config`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct NormalizationReport {
+    pub changes: Vec<NormalizationChange>,
+}
+
+impl NormalizationReport {
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    fn record(&mut self, field: &str, old_value: impl std::fmt::Debug, new_value: impl std::fmt::Debug) {
+        self.changes.push(NormalizationChange {
+            field: field.to_string(),
+            old_value: format!("{:?}", old_value),
+            new_value: format!("{:?}", new_value),
+        });
+    }
+}
+
+/// Attempts safe, well-defined auto-repairs for the conditions `ConfigValidator` knows about,
+/// rewriting `config` in place and returning a report of every field it touched (old value ->
+/// new value).
+///
+/// `validate_config` stays the pure, side-effect-free checker; both it and this function call
+/// into the same `config_rules` predicates to decide whether a condition holds, so the two
+/// can't drift apart. Anything this function can't safely auto-repair is left for
+/// `validate_config` to report as an error.
+pub fn normalize_config(config: &mut Config) -> NormalizationReport {
+    let mut report = NormalizationReport::default();
+    let defaults = Config::default();
+
+    if config_rules::archive_requires_save_trie_changes(config) {
+        report.record("save_trie_changes", config.save_trie_changes, Some(true));
+        config.save_trie_changes = Some(true);
+    }
+
+    if config_rules::gc_blocks_limit_is_zero(config) {
+        report.record("gc.gc_blocks_limit", config.gc.gc_blocks_limit, defaults.gc.gc_blocks_limit);
+        config.gc.gc_blocks_limit = defaults.gc.gc_blocks_limit;
+    }
+
+    if config_rules::gc_fork_clean_step_is_zero(config) {
+        report.record(
+            "gc.gc_fork_clean_step",
+            config.gc.gc_fork_clean_step,
+            defaults.gc.gc_fork_clean_step,
+        );
+        config.gc.gc_fork_clean_step = defaults.gc.gc_fork_clean_step;
+    }
+
+    if config_rules::gc_num_epochs_to_keep_is_zero(config) {
+        report.record(
+            "gc.gc_num_epochs_to_keep",
+            config.gc.gc_num_epochs_to_keep,
+            defaults.gc.gc_num_epochs_to_keep,
+        );
+        config.gc.gc_num_epochs_to_keep = defaults.gc.gc_num_epochs_to_keep;
+    }
+
+    if config_rules::min_gt_max_block_production_delay(config) {
+        report.record(
+            "consensus.{min,max}_block_production_delay",
+            (config.consensus.min_block_production_delay, config.consensus.max_block_production_delay),
+            (config.consensus.max_block_production_delay, config.consensus.min_block_production_delay),
+        );
+        std::mem::swap(
+            &mut config.consensus.min_block_production_delay,
+            &mut config.consensus.max_block_production_delay,
+        );
+    }
+
+    report
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_normalize_save_trie_changes() {
+        let mut config = Config::default();
+        config.archive = false;
+        config.save_trie_changes = Some(false);
+        let report = normalize_config(&mut config);
+        assert_eq!(config.save_trie_changes, Some(true));
+        assert_eq!(report.changes.len(), 1);
+        assert_eq!(report.changes[0].field, "save_trie_changes");
+    }
+
+    #[test]
+    fn test_normalize_zero_gc_values_to_defaults() {
+        let mut config = Config::default();
+        let defaults = Config::default();
+        config.gc.gc_blocks_limit = 0;
+        config.gc.gc_fork_clean_step = 0;
+        config.gc.gc_num_epochs_to_keep = 0;
+        let report = normalize_config(&mut config);
+        assert_eq!(config.gc.gc_blocks_limit, defaults.gc.gc_blocks_limit);
+        assert_eq!(config.gc.gc_fork_clean_step, defaults.gc.gc_fork_clean_step);
+        assert_eq!(config.gc.gc_num_epochs_to_keep, defaults.gc.gc_num_epochs_to_keep);
+        assert_eq!(report.changes.len(), 3);
+    }
+
+    #[test]
+    fn test_normalize_swaps_inverted_block_production_delays() {
+        let mut config = Config::default();
+        let (min, max) =
+            (config.consensus.min_block_production_delay, config.consensus.max_block_production_delay);
+        config.consensus.min_block_production_delay = max;
+        config.consensus.max_block_production_delay = min;
+        let report = normalize_config(&mut config);
+        assert_eq!(config.consensus.min_block_production_delay, min);
+        assert_eq!(config.consensus.max_block_production_delay, max);
+        assert_eq!(report.changes.len(), 1);
+    }
+
+    #[test]
+    fn test_normalize_is_a_noop_for_a_clean_default_config() {
+        let mut config = Config::default();
+        let report = normalize_config(&mut config);
+        assert!(report.is_empty());
+    }
+}
@@ -0,0 +1,25 @@
+//! Predicates shared by `config_validate` (which reports violations) and `config_normalize`
+//! (which repairs them). Keeping the conditions in one place means the validator and the
+//! fixer can't silently drift apart.
+
+use crate::config::Config;
+
+pub(crate) fn archive_requires_save_trie_changes(config: &Config) -> bool {
+    config.archive == false && config.save_trie_changes == Some(false)
+}
+
+pub(crate) fn gc_blocks_limit_is_zero(config: &Config) -> bool {
+    config.gc.gc_blocks_limit == 0
+}
+
+pub(crate) fn gc_fork_clean_step_is_zero(config: &Config) -> bool {
+    config.gc.gc_fork_clean_step == 0
+}
+
+pub(crate) fn gc_num_epochs_to_keep_is_zero(config: &Config) -> bool {
+    config.gc.gc_num_epochs_to_keep == 0
+}
+
+pub(crate) fn min_gt_max_block_production_delay(config: &Config) -> bool {
+    config.consensus.min_block_production_delay > config.consensus.max_block_production_delay
+}
@@ -1,12 +1,44 @@
 use near_config_utils::{ValidationError, ValidationErrors};
 
 use crate::config::Config;
+use crate::config_rules;
+
+/// Hard ceiling imposed by the transport layer (the length-prefixed framing codec used by the
+/// peer-to-peer connections). No configured payload size may exceed this.
+const MAX_TRANSPORT_FRAME_SIZE: u64 = 512 * 1024 * 1024;
+
+/// Non-fatal findings attached to a successful [`validate_config`] call. The config is safe
+/// to boot with, but the caller should log these at `WARN` so operators notice suspicious
+/// settings before they bite.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct ValidationReport {
+    pub warnings: Vec<String>,
+}
+
+impl ValidationReport {
+    fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
 
 /// Validate Config extracted from config.json.
 /// This function does not panic. It returns the error if any validation fails.
-pub fn validate_config(config: &Config) -> Result<(), ValidationError> {
+pub fn validate_config(config: &Config) -> Result<ValidationReport, ValidationError> {
+    validate_config_with_mode(config, false)
+}
+
+/// Like [`validate_config`], but promotes every warning to a hard error. Intended for CI and
+/// testnet deployments that want to fail fast on anything suspicious rather than just logging it.
+pub fn validate_config_strict(config: &Config) -> Result<ValidationReport, ValidationError> {
+    validate_config_with_mode(config, true)
+}
+
+fn validate_config_with_mode(
+    config: &Config,
+    strict: bool,
+) -> Result<ValidationReport, ValidationError> {
     let mut validation_errors = ValidationErrors::new();
-    let mut config_validator = ConfigValidator::new(config, &mut validation_errors);
+    let mut config_validator = ConfigValidator::new(config, &mut validation_errors, strict);
     tracing::info!(target: "config", "Validating Config, extracted from config.json...");
     config_validator.validate()
 }
@@ -14,28 +46,38 @@ pub fn validate_config(config: &Config) -> Result<(), ValidationError> {
 struct ConfigValidator<'a> {
     config: &'a Config,
     validation_errors: &'a mut ValidationErrors,
+    strict: bool,
+    warnings: Vec<String>,
 }
 
 impl<'a> ConfigValidator<'a> {
-    fn new(config: &'a Config, validation_errors: &'a mut ValidationErrors) -> Self {
-        Self { config, validation_errors }
+    fn new(config: &'a Config, validation_errors: &'a mut ValidationErrors, strict: bool) -> Self {
+        Self { config, validation_errors, strict, warnings: Vec::new() }
     }
 
-    fn validate(&mut self) -> Result<(), ValidationError> {
+    fn validate(&mut self) -> Result<ValidationReport, ValidationError> {
         self.validate_all_conditions();
         self.result_with_full_error()
     }
 
+    /// Records a non-fatal finding. Under `strict` mode this is promoted to a hard error
+    /// instead, so CI and testnet configs can't silently carry suspicious settings.
+    fn push_config_semantics_warning(&mut self, message: String) {
+        if self.strict {
+            self.validation_errors.push_config_semantics_error(message);
+        } else {
+            self.warnings.push(message);
+        }
+    }
+
     /// this function would check all conditions, and add all error messages to ConfigValidator.errors
     fn validate_all_conditions(&mut self) {
-        if self.config.archive == false && self.config.save_trie_changes == Some(false) {
+        if config_rules::archive_requires_save_trie_changes(self.config) {
             let error_message = format!("Configuration with archive = false and save_trie_changes = false is not supported because non-archival nodes must save trie changes in order to do do garbage collection.");
             self.validation_errors.push_config_semantics_error(error_message)
         }
 
-        if self.config.consensus.min_block_production_delay
-            > self.config.consensus.max_block_production_delay
-        {
+        if config_rules::min_gt_max_block_production_delay(self.config) {
             let error_message = format!(
                 "min_block_production_delay: {:?} is greater than max_block_production_delay: {:?}",
                 self.config.consensus.min_block_production_delay,
@@ -61,18 +103,112 @@ impl<'a> ConfigValidator<'a> {
             self.validation_errors.push_config_semantics_error(error_message)
         }
 
-        if self.config.gc.gc_blocks_limit == 0
-            || self.config.gc.gc_fork_clean_step == 0
-            || self.config.gc.gc_num_epochs_to_keep == 0
+        if config_rules::gc_blocks_limit_is_zero(self.config)
+            || config_rules::gc_fork_clean_step_is_zero(self.config)
+            || config_rules::gc_num_epochs_to_keep_is_zero(self.config)
         {
             let error_message = format!("gc config values should all be greater than 0, but gc_blocks_limit is {:?}, gc_fork_clean_step is {}, gc_num_epochs_to_keep is {}.", self.config.gc.gc_blocks_limit, self.config.gc.gc_fork_clean_step, self.config.gc.gc_num_epochs_to_keep);
             self.validation_errors.push_config_semantics_error(error_message)
         }
+
+        // A very low gc_num_epochs_to_keep is not invalid, but it makes nodes unable to serve
+        // state sync or view historical data a few epochs back, which usually isn't intended.
+        if self.config.gc.gc_num_epochs_to_keep > 0 && self.config.gc.gc_num_epochs_to_keep < 3 {
+            let warning_message = format!(
+                "gc.gc_num_epochs_to_keep is set to {}, which is unusually low and may prevent this node from serving state sync requests",
+                self.config.gc.gc_num_epochs_to_keep
+            );
+            self.push_config_semantics_warning(warning_message)
+        }
+
+        self.validate_network_config();
+        self.validate_payload_size_config();
     }
 
-    fn result_with_full_error(&self) -> Result<(), ValidationError> {
+    /// Checks that the configured max message/chunk payload size is consistent with the
+    /// network actor's buffering: a payload that doesn't fit in the configured buffer can
+    /// deadlock or be silently dropped under load, and a payload above the transport's own
+    /// framing limit can never be sent at all.
+    fn validate_payload_size_config(&mut self) {
+        let Some(max_payload_size) = self.config.network.max_payload_size else {
+            return;
+        };
+
+        if self.config.network.peer_recv_buffer_size < max_payload_size {
+            let error_message = format!(
+                "network.peer_recv_buffer_size ({} bytes) is smaller than network.max_payload_size ({} bytes); this combination can deadlock or silently drop large messages",
+                self.config.network.peer_recv_buffer_size, max_payload_size
+            );
+            self.validation_errors.push_config_semantics_error(error_message);
+        }
+
+        if max_payload_size > MAX_TRANSPORT_FRAME_SIZE {
+            let error_message = format!(
+                "network.max_payload_size ({}) exceeds the transport frame limit ({})",
+                max_payload_size, MAX_TRANSPORT_FRAME_SIZE
+            );
+            self.validation_errors.push_config_semantics_error(error_message);
+        }
+    }
+
+    /// Checks the boot-node and whitelist-node lists for duplicate entries, malformed
+    /// `peer_id@ip:port` entries, and entries that point back at this node's own address.
+    fn validate_network_config(&mut self) {
+        self.validate_peer_list(self.config.network.boot_nodes.clone(), "boot_nodes");
+        self.validate_peer_list(self.config.network.whitelist_nodes.clone(), "whitelist_nodes");
+    }
+
+    fn validate_peer_list(&mut self, peers: String, field_name: &str) {
+        let own_addrs: Vec<&str> = non_empty(&self.config.network.addr)
+            .into_iter()
+            .chain(non_empty(&self.config.network.external_address))
+            .collect();
+
+        let mut seen = std::collections::HashSet::new();
+        for entry in peers.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            if !seen.insert(entry) {
+                let error_message =
+                    format!("network.{} contains a duplicate entry: {:?}", field_name, entry);
+                self.validation_errors.push_config_semantics_error(error_message);
+                continue;
+            }
+
+            match entry.split_once('@') {
+                None => {
+                    let error_message = format!(
+                        "network.{} entry {:?} is not a valid peer_id@ip:port address",
+                        field_name, entry
+                    );
+                    self.validation_errors.push_config_semantics_error(error_message);
+                }
+                Some((_peer_id, socket_addr)) => {
+                    if socket_addr.parse::<std::net::SocketAddr>().is_err() {
+                        let error_message = format!(
+                            "network.{} entry {:?} has an invalid socket address {:?}",
+                            field_name, entry, socket_addr
+                        );
+                        self.validation_errors.push_config_semantics_error(error_message);
+                    } else if own_addrs.contains(&socket_addr) {
+                        let error_message = format!(
+                            "network.{} entry {:?} refers to this node's own address {:?}; a node should not list itself as a peer",
+                            field_name, entry, socket_addr
+                        );
+                        self.validation_errors.push_config_semantics_error(error_message);
+                    }
+                }
+            }
+        }
+    }
+
+    fn result_with_full_error(&self) -> Result<ValidationReport, ValidationError> {
         if self.validation_errors.is_empty() {
-            Ok(())
+            let report = ValidationReport { warnings: self.warnings.clone() };
+            if !report.is_empty() {
+                for warning in &report.warnings {
+                    tracing::warn!(target: "config", "config.json semantic warning: {}", warning);
+                }
+            }
+            Ok(report)
         } else {
             let full_err_msg = self.validation_errors.generate_error_message_per_type().unwrap();
             Err(ValidationError::ConfigSemanticsError { error_message: full_err_msg }.into())
@@ -80,6 +216,14 @@ impl<'a> ConfigValidator<'a> {
     }
 }
 
+fn non_empty(s: &str) -> Option<&str> {
+    if s.is_empty() {
+        None
+    } else {
+        Some(s)
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -120,4 +264,80 @@ mod test {
         config.tracked_shards.push(20);
         validate_config(&config).unwrap();
     }
+
+    #[test]
+    fn test_low_gc_num_epochs_to_keep_is_a_warning_not_an_error() {
+        let mut config = Config::default();
+        config.gc.gc_num_epochs_to_keep = 1;
+        config.tracked_shards.push(20);
+        let report = validate_config(&config).unwrap();
+        assert_eq!(report.warnings.len(), 1);
+        assert!(report.warnings[0].contains("gc_num_epochs_to_keep"));
+    }
+
+    #[test]
+    #[should_panic(expected = "gc_num_epochs_to_keep")]
+    fn test_low_gc_num_epochs_to_keep_is_an_error_in_strict_mode() {
+        let mut config = Config::default();
+        config.gc.gc_num_epochs_to_keep = 1;
+        config.tracked_shards.push(20);
+        validate_config_strict(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "contains a duplicate entry")]
+    fn test_duplicate_boot_node_rejected() {
+        let mut config = Config::default();
+        config.tracked_shards.push(20);
+        config.network.boot_nodes =
+            "ed25519:abc@1.2.3.4:24567,ed25519:abc@1.2.3.4:24567".to_string();
+        validate_config(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "refers to this node's own address")]
+    fn test_self_as_boot_node_rejected() {
+        let mut config = Config::default();
+        config.tracked_shards.push(20);
+        config.network.addr = "1.2.3.4:24567".to_string();
+        config.network.boot_nodes = "ed25519:abc@1.2.3.4:24567".to_string();
+        validate_config(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "is not a valid peer_id@ip:port address")]
+    fn test_malformed_boot_node_rejected() {
+        let mut config = Config::default();
+        config.tracked_shards.push(20);
+        config.network.boot_nodes = "not-a-valid-entry".to_string();
+        validate_config(&config).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "is smaller than network.max_payload_size")]
+    fn test_buffer_size_smaller_than_max_payload_size_rejected() {
+        let mut config = Config::default();
+        config.tracked_shards.push(20);
+        config.network.max_payload_size = Some(10 * 1024 * 1024);
+        config.network.peer_recv_buffer_size = 1024 * 1024;
+        validate_config(&config).unwrap();
+    }
+
+    #[test]
+    fn test_default_buffer_size_accommodates_a_realistic_max_payload_size() {
+        let mut config = Config::default();
+        config.tracked_shards.push(20);
+        config.network.max_payload_size = Some(1024 * 1024);
+        assert!(validate_config(&config).is_ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "exceeds the transport frame limit")]
+    fn test_max_payload_size_above_transport_limit_rejected() {
+        let mut config = Config::default();
+        config.tracked_shards.push(20);
+        config.network.max_payload_size = Some(MAX_TRANSPORT_FRAME_SIZE + 1);
+        config.network.peer_recv_buffer_size = MAX_TRANSPORT_FRAME_SIZE + 1;
+        validate_config(&config).unwrap();
+    }
 }
\ No newline at end of file
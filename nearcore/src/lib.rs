@@ -0,0 +1,43 @@
+mod config;
+mod config_migrations;
+mod config_normalize;
+mod config_rules;
+mod config_validate;
+
+use std::path::Path;
+
+use near_config_utils::ValidationError;
+
+pub use config::Config;
+pub use config_migrations::{migrate_config, CONFIG_VERSION};
+pub use config_normalize::{normalize_config, NormalizationChange, NormalizationReport};
+pub use config_validate::{validate_config, validate_config_strict, ValidationReport};
+
+const CONFIG_FILENAME: &str = "config.json";
+
+/// Reads `config.json` out of `dir`, migrates it to [`CONFIG_VERSION`], and validates the
+/// result. This is the single entry point nodes should use to load their config, since it's
+/// the only path that runs `migrate_config` before deserialization.
+///
+/// Returns the parsed [`Config`] together with the [`ValidationReport`] produced while
+/// validating it, so the caller can log (or otherwise surface) any warnings instead of them
+/// being silently dropped.
+pub fn load_config(dir: &Path) -> Result<(Config, ValidationReport), ValidationError> {
+    let path = dir.join(CONFIG_FILENAME);
+    let raw = std::fs::read_to_string(&path).map_err(|err| {
+        ValidationError::ConfigSemanticsError {
+            error_message: format!("failed to read {}: {}", path.display(), err),
+        }
+    })?;
+    let value: serde_json::Value =
+        serde_json::from_str(&raw).map_err(|err| ValidationError::ConfigSemanticsError {
+            error_message: format!("failed to parse {} as JSON: {}", path.display(), err),
+        })?;
+    let migrated = migrate_config(value)?;
+    let config: Config =
+        serde_json::from_value(migrated).map_err(|err| ValidationError::ConfigSemanticsError {
+            error_message: format!("failed to deserialize {}: {}", path.display(), err),
+        })?;
+    let report = validate_config(&config)?;
+    Ok((config, report))
+}